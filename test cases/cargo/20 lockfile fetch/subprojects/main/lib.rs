@@ -0,0 +1,7 @@
+extern crate libothername;
+extern crate mybar;
+
+pub fn run() -> i32 {
+    assert_eq!(libothername::stuff(), 42);
+    mybar::VALUE
+}
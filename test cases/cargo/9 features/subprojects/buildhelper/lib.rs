@@ -0,0 +1,10 @@
+extern crate sub1;
+
+// buildhelper is only ever pulled in as a build-dependency, so it sits in
+// the host graph: its own dependency on sub1 must not be unified with
+// sub2's (target graph) dependency on sub1, even though resolver v2 keeps
+// them as the same crate in the build.
+pub fn host_check() -> i32 {
+    assert_eq!(sub1::returncode(), 1);
+    0
+}
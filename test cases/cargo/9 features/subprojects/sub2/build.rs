@@ -0,0 +1,5 @@
+extern crate buildhelper;
+
+fn main() {
+    assert_eq!(buildhelper::host_check(), 0);
+}
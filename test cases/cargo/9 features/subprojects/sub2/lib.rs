@@ -0,0 +1,20 @@
+extern crate sub1;
+
+#[cfg(feature = "optional_dep")]
+extern crate optional_dep;
+
+// "extra" enables sub1's "test_feature" via the "crate/feature" syntax, and
+// pulls in an optional dependency via "dep:optional_dep".
+#[cfg(feature = "extra")]
+pub fn check() -> i32 {
+    assert_eq!(sub1::returncode(), 0);
+    #[cfg(feature = "optional_dep")]
+    assert_eq!(optional_dep::value(), 1);
+    0
+}
+
+#[cfg(not(feature = "extra"))]
+pub fn check() -> i32 {
+    assert_eq!(sub1::returncode(), 1);
+    0
+}
@@ -1,8 +1,14 @@
 use std::env;
+use std::fs;
+use std::path::Path;
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rustc-cfg=FOO");
     println!("cargo:rustc-cfg=BAR=val");
     assert!(env::var("CARGO_FEATURE_MYFEATURE").is_ok());
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&out_dir).join("generated.rs");
+    fs::write(dest, "fn generated_value() -> i32 { 42 }\n").unwrap();
 }
@@ -0,0 +1,9 @@
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+fn main() {
+    #[cfg(FOO)]
+    assert_eq!(generated_value(), 42);
+
+    #[cfg(BAR = "val")]
+    println!("bar was set to val");
+}
@@ -0,0 +1,16 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        fn adder_add(number: i32, other: i32) -> i32;
+    }
+
+    unsafe extern "C++" {
+        include!("cxxbridge/greeter.h");
+
+        fn greet(name: &str) -> String;
+    }
+}
+
+fn adder_add(number: i32, other: i32) -> i32 {
+    number + other
+}
@@ -0,0 +1,8 @@
+/// Adds two numbers together.
+///
+/// ```
+/// assert_eq!(doctest::add(2, 2), 4);
+/// ```
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}